@@ -0,0 +1,123 @@
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::{pyclass, pyfunction, pymethods, Py, PyAny, PyErr, PyResult, Python};
+use std::sync::mpsc as std_mpsc;
+use std::sync::{Arc, Mutex, OnceLock};
+use tokio::runtime::Runtime;
+use tokio::sync::broadcast;
+use tracing_subscriber::fmt::MakeWriter;
+
+static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+static SHUTDOWN: OnceLock<broadcast::Sender<()>> = OnceLock::new();
+
+/// The process-wide Tokio runtime that every `Session` spawns its I/O loop
+/// onto, instead of each session paying for a dedicated OS thread/runtime.
+pub(crate) fn tokio() -> &'static Runtime {
+    RUNTIME.get_or_init(|| Runtime::new().expect("Failed to create Tokio runtime"))
+}
+
+/// Subscribe to the process-wide shutdown signal that `Driver::stop` fires.
+pub(crate) fn shutdown_signal() -> broadcast::Receiver<()> {
+    SHUTDOWN.get_or_init(|| broadcast::channel(1).0).subscribe()
+}
+
+/// Shared slot holding the live sender, if logging is still running.
+///
+/// The global `tracing` subscriber keeps its own clone of a `PySinkMaker`
+/// for as long as the process lives, so a plain `Option` on `Driver` alone
+/// can never close the channel - `Driver::stop` needs a handle into the
+/// *same* slot the subscriber writes through, and clears it there instead.
+type LogSlot = Arc<Mutex<Option<std_mpsc::Sender<String>>>>;
+
+/// Writer that forwards each formatted tracing line to the background
+/// task driving the Python logger callback, until `Driver::stop` clears
+/// the slot, after which it silently drops lines.
+struct PySink(LogSlot);
+
+impl std::io::Write for PySink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if let Some(tx) = self.0.lock().unwrap().as_ref() {
+            tx.send(String::from_utf8_lossy(buf).into_owned()).ok();
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+struct PySinkMaker(LogSlot);
+
+impl<'a> MakeWriter<'a> for PySinkMaker {
+    type Writer = PySink;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        PySink(self.0.clone())
+    }
+}
+
+/// The single controlled shutdown point for every `Session` spawned onto
+/// the shared runtime, plus the log-forwarding task started by [`init`].
+///
+/// This stops logging and tells every `Session` to shut down; it does
+/// *not* tear down the process-wide runtime itself (see [`tokio`]) - that
+/// runtime is a `'static` `OnceLock`, shared by any `Session` that might
+/// still be mid-drop, and outlives any one `Driver`.
+#[pyclass]
+pub struct Driver {
+    log_tx: LogSlot,
+}
+
+#[pymethods]
+impl Driver {
+    /// Stop forwarding logs and signal every `Session` on the shared
+    /// runtime to shut down.
+    ///
+    /// Clearing the slot drops the last live `Sender<String>` - the
+    /// subscriber's own clone was replaced by this same shared slot at
+    /// `init` time, so this is what actually closes the channel and lets
+    /// the forwarding task's `rx.recv()` loop exit.
+    fn stop(&mut self) {
+        self.log_tx.lock().unwrap().take();
+        SHUTDOWN.get_or_init(|| broadcast::channel(1).0).send(()).ok();
+    }
+}
+
+/// Install a `tracing-subscriber` layer that forwards every formatted log
+/// line from the underlying `hotfix` session (logon/logout, sequence
+/// resets, reject reasons, ...) to `logger_cb`, instead of it going to
+/// stderr via `eprintln!`.
+///
+/// Log delivery and session I/O share one executor: the background
+/// forwarding task runs on the same process-wide runtime as every
+/// `Session`.
+///
+/// Errors rather than panicking if a global subscriber is already
+/// installed, so calling `init` again (e.g. to change `debug`) is a
+/// recoverable mistake rather than a hard crash.
+#[pyfunction]
+pub fn init(logger_cb: Py<PyAny>, debug: bool) -> PyResult<Driver> {
+    let (tx, rx) = std_mpsc::channel::<String>();
+    let log_tx: LogSlot = Arc::new(Mutex::new(Some(tx)));
+
+    let level = if debug { tracing::Level::DEBUG } else { tracing::Level::INFO };
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(PySinkMaker(log_tx.clone()))
+        .with_ansi(false)
+        .try_init()
+        .map_err(|e| PyErr::new::<PyRuntimeError, _>(e.to_string()))?;
+
+    tokio().spawn_blocking(move || {
+        while let Ok(line) = rx.recv() {
+            Python::attach(|py| {
+                if let Err(e) = logger_cb.call1(py, (line.trim_end().to_string(),)) {
+                    eprintln!("Error calling logger callback: {}", e);
+                }
+            });
+        }
+    });
+
+    Ok(Driver { log_tx })
+}
@@ -1,17 +1,26 @@
 mod message;
 mod session;
 mod repeating_group;
+mod promise;
+mod driver;
+mod post_office;
 
 use pyo3::prelude::*;
 
 #[pymodule(name = "hotfix_core")]
 mod hotfix_core {
     #[pymodule_export]
-    use super::message::{encode_message, Message};
+    use super::message::{encode_message, Message, MessageFieldIter};
 
     #[pymodule_export]
     use super::session::{Session, InboundDecision, OutboundDecision};
 
     #[pymodule_export]
     use super::repeating_group::RepeatingGroup;
+
+    #[pymodule_export]
+    use super::promise::Promise;
+
+    #[pymodule_export]
+    use super::driver::{init, Driver};
 }
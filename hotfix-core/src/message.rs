@@ -2,7 +2,7 @@ use hotfix::message::FixMessage;
 use hotfix_message::{Field, FieldMap, Part, TagU32};
 use hotfix_message::message::Config;
 use hotfix_message::session_fields::MSG_TYPE;
-use pyo3::{pyclass, pyfunction, pymethods, PyClassInitializer, PyRef, PyResult};
+use pyo3::{pyclass, pyfunction, pymethods, Py, PyClassInitializer, PyRef, PyRefMut, PyResult};
 use crate::repeating_group::RepeatingGroup;
 
 #[pyclass(subclass)]
@@ -40,6 +40,77 @@ impl Message {
 
         Ok(())
     }
+
+    /// Look up a field as a string, e.g. `get(35)` for the message type of a
+    /// received message (a `Message` built in Python keeps its type apart
+    /// from its fields, so there tag 35 is present only if inserted).
+    fn get(&self, tag: u32) -> Option<String> {
+        let field = self.field_map.fields.get(&TagU32::new(tag)?)?;
+        Some(String::from_utf8_lossy(&field.value).into_owned())
+    }
+
+    /// Look up a field and parse it as an integer.
+    fn get_int(&self, tag: u32) -> Option<i64> {
+        self.get(tag)?.parse().ok()
+    }
+
+    /// Look up a field and parse it as a float.
+    fn get_float(&self, tag: u32) -> Option<f64> {
+        self.get(tag)?.parse().ok()
+    }
+
+    /// Whether the message carries a top-level field with this tag; fields
+    /// inside repeating groups are not checked.
+    fn contains(&self, tag: u32) -> bool {
+        TagU32::new(tag).map(|t| self.field_map.fields.contains_key(&t)).unwrap_or(false)
+    }
+
+    /// Walk the repeating group instances starting at `start_tag`, if any.
+    fn get_groups(&self, start_tag: u32) -> Vec<RepeatingGroup> {
+        TagU32::new(start_tag)
+            .and_then(|t| self.field_map.groups.get(&t))
+            .map(|groups| groups.iter().map(|inner| RepeatingGroup { inner: inner.clone() }).collect())
+            .unwrap_or_default()
+    }
+
+    /// Iterate over every top-level `(tag, value)` pair in the message;
+    /// fields inside repeating groups are not included.
+    fn __iter__(&self) -> MessageFieldIter {
+        let items = self.field_map.fields.values()
+            .map(|field| (field.tag.get(), String::from_utf8_lossy(&field.value).into_owned()))
+            .collect();
+        MessageFieldIter { items, index: 0 }
+    }
+}
+
+/// Iterator returned by `Message.__iter__`.
+#[pyclass]
+pub struct MessageFieldIter {
+    items: Vec<(u32, String)>,
+    index: usize,
+}
+
+#[pymethods]
+impl MessageFieldIter {
+    fn __iter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<(u32, String)> {
+        let item = slf.items.get(slf.index).cloned();
+        if item.is_some() {
+            slf.index += 1;
+        }
+        item
+    }
+}
+
+impl Message {
+    /// Read-only access to the decoded fields, used internally (e.g. by the
+    /// post office) to match a reply against the tag/value it echoes back.
+    pub(crate) fn field_map(&self) -> &FieldMap {
+        &self.field_map
+    }
 }
 
 #[pyfunction]
@@ -67,6 +138,20 @@ impl FixMessage for Message {
 
     fn parse(msg: &hotfix_message::message::Message) -> Self {
         let message_type: &str = msg.header().get(MSG_TYPE).unwrap();
-        Message { message_type: message_type.to_string(), field_map: FieldMap::default() }
+        let message_type = message_type.to_string();
+
+        // Copy every header and body field (and reconstruct their repeating
+        // groups) so Python sees the full decoded message, not just the type.
+        let mut field_map = FieldMap::default();
+        for part in [msg.header().get_field_map(), msg.body().get_field_map()] {
+            for (_, field) in &part.fields {
+                field_map.insert(field.clone());
+            }
+            for (start_tag, groups) in &part.groups {
+                field_map.set_groups(*start_tag, groups.clone());
+            }
+        }
+
+        Message { message_type, field_map }
     }
 }
\ No newline at end of file
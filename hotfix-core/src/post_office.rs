@@ -0,0 +1,68 @@
+use hotfix_message::TagU32;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tokio::sync::oneshot;
+use crate::message::Message;
+
+/// The tag/value pair a reply is expected to echo back, e.g. 11/ClOrdID.
+pub(crate) type CorrelationKey = (TagU32, Vec<u8>);
+
+/// Mailbox of in-flight request/reply correlations.
+///
+/// Trading flows are request/reply (NewOrderSingle -> ExecutionReport), but
+/// the inbound dispatch loop only sees a stream of messages. The post
+/// office lets `send_message` register a waiter for the reply it expects,
+/// keyed by the tag/value that reply will carry, and have it fulfilled
+/// before the message ever reaches `on_inbound_message`.
+#[derive(Default)]
+pub(crate) struct PostOffice {
+    waiters: Mutex<HashMap<CorrelationKey, oneshot::Sender<Message>>>,
+}
+
+impl PostOffice {
+    /// Register a waiter for `key`. Errors if a reply is already in flight
+    /// for the same tag/value, rather than silently dropping the earlier
+    /// waiter's sender (which would resolve that `Promise` with a spurious
+    /// "No response from session").
+    pub(crate) fn register(&self, key: CorrelationKey, reply_tx: oneshot::Sender<Message>) -> Result<(), String> {
+        let mut waiters = self.waiters.lock().unwrap();
+        if waiters.contains_key(&key) {
+            return Err(format!("A reply is already awaited for tag {}", key.0.get()));
+        }
+        waiters.insert(key, reply_tx);
+        Ok(())
+    }
+
+    /// Check `message` against every registered waiter and fulfill the
+    /// first match. Returns `true` if the message was claimed this way, so
+    /// the caller should skip the `on_inbound_message` fallback.
+    pub(crate) fn dispatch(&self, message: &Message) -> bool {
+        let mut waiters = self.waiters.lock().unwrap();
+        if waiters.is_empty() {
+            return false;
+        }
+
+        let matched_key = waiters.keys()
+            .find(|(tag, value)| {
+                message.field_map().fields.get(tag)
+                    .map(|field| &field.value == value)
+                    .unwrap_or(false)
+            })
+            .cloned();
+
+        match matched_key {
+            Some(key) => {
+                if let Some(reply_tx) = waiters.remove(&key) {
+                    reply_tx.send(message.clone()).ok();
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Drop a waiter that timed out so the map doesn't leak stale entries.
+    pub(crate) fn cancel(&self, key: &CorrelationKey) {
+        self.waiters.lock().unwrap().remove(key);
+    }
+}
@@ -0,0 +1,154 @@
+use pyo3::exceptions::{PyRuntimeError, PyTimeoutError};
+use pyo3::{pyclass, pymethods, Py, PyAny, PyErr, PyResult, Python};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::oneshot;
+use crate::message::Message;
+use crate::post_office::{CorrelationKey, PostOffice};
+
+/// State of a plain send acknowledgement — tracked explicitly (rather than
+/// just an `Option<Receiver<_>>`) so a non-blocking `is_done` peek can
+/// cache what it observes instead of losing it.
+enum AckState {
+    Pending(oneshot::Receiver<Result<(), String>>),
+    Ready(Result<(), String>),
+    Taken,
+}
+
+/// The post office waiter backing a correlated reply, kept around so a
+/// timed-out or dropped `Promise` can clean up its map entry rather than
+/// leaking it.
+struct ReplyWaiter {
+    post_office: Arc<PostOffice>,
+    key: CorrelationKey,
+}
+
+enum ReplyState {
+    Pending(oneshot::Receiver<Message>, ReplyWaiter),
+    Ready(Result<Message, String>),
+    Taken,
+}
+
+enum Kind {
+    Ack(AckState),
+    Reply(ReplyState),
+}
+
+/// A handle to an in-flight `send_message` call.
+///
+/// Returned immediately so Python can fire off many messages and collect
+/// their results later, rather than blocking on each one in turn.
+#[pyclass]
+pub struct Promise {
+    kind: Kind,
+}
+
+impl Promise {
+    pub(crate) fn ack(receiver: oneshot::Receiver<Result<(), String>>) -> Self {
+        Promise { kind: Kind::Ack(AckState::Pending(receiver)) }
+    }
+
+    pub(crate) fn reply(receiver: oneshot::Receiver<Message>, post_office: Arc<PostOffice>, key: CorrelationKey) -> Self {
+        Promise { kind: Kind::Reply(ReplyState::Pending(receiver, ReplyWaiter { post_office, key })) }
+    }
+}
+
+#[pymethods]
+impl Promise {
+    /// Block until the result arrives, releasing the GIL while waiting.
+    ///
+    /// If `timeout` (in seconds) is given and elapses first, raises a
+    /// `TimeoutError` and leaves the `Promise` consumed either way.
+    #[pyo3(signature = (timeout=None))]
+    fn wait(&mut self, py: Python<'_>, timeout: Option<f64>) -> PyResult<Py<PyAny>> {
+        match &mut self.kind {
+            Kind::Ack(state) => match std::mem::replace(state, AckState::Taken) {
+                AckState::Pending(receiver) => Self::recv(py, receiver, timeout)?
+                    .map(|_| py.None())
+                    .map_err(|e| PyErr::new::<PyRuntimeError, _>(e)),
+                AckState::Ready(result) => result
+                    .map(|_| py.None())
+                    .map_err(|e| PyErr::new::<PyRuntimeError, _>(e)),
+                AckState::Taken => Err(PyErr::new::<PyRuntimeError, _>("Promise has already been awaited")),
+            },
+            Kind::Reply(state) => match std::mem::replace(state, ReplyState::Taken) {
+                ReplyState::Pending(receiver, waiter) => {
+                    let result = Self::recv(py, receiver, timeout);
+                    if result.is_err() {
+                        // Timed out, or the sender was dropped without ever
+                        // replying - either way the waiter is never coming
+                        // back for it, so drop it from the post office too.
+                        waiter.post_office.cancel(&waiter.key);
+                    }
+                    result.and_then(|message| Py::new(py, message).map(Py::into_any))
+                }
+                ReplyState::Ready(result) => result
+                    .map_err(|e| PyErr::new::<PyRuntimeError, _>(e))
+                    .and_then(|message| Py::new(py, message).map(Py::into_any)),
+                ReplyState::Taken => Err(PyErr::new::<PyRuntimeError, _>("Promise has already been awaited")),
+            },
+        }
+    }
+
+    /// Peek the channel without blocking.
+    ///
+    /// Stock `tokio::sync::oneshot::Receiver` has no non-consuming peek, so
+    /// this uses `try_recv` and caches whatever it observes — a ready
+    /// value, or (if the channel closed with nothing sent) a terminal
+    /// "no response" error — rather than discarding it. Without caching
+    /// the error, a subsequent `wait()` would see `Taken` and raise
+    /// "Promise has already been awaited" instead of the real cause.
+    fn is_done(&mut self) -> bool {
+        match &mut self.kind {
+            Kind::Ack(state) => match std::mem::replace(state, AckState::Taken) {
+                AckState::Pending(mut receiver) => match receiver.try_recv() {
+                    Ok(value) => { *state = AckState::Ready(value); true }
+                    Err(oneshot::error::TryRecvError::Empty) => { *state = AckState::Pending(receiver); false }
+                    Err(oneshot::error::TryRecvError::Closed) => {
+                        *state = AckState::Ready(Err("No response from session".to_string()));
+                        true
+                    }
+                },
+                ready @ AckState::Ready(_) => { *state = ready; true }
+                AckState::Taken => true,
+            },
+            Kind::Reply(state) => match std::mem::replace(state, ReplyState::Taken) {
+                ReplyState::Pending(mut receiver, waiter) => match receiver.try_recv() {
+                    Ok(message) => { *state = ReplyState::Ready(Ok(message)); true }
+                    Err(oneshot::error::TryRecvError::Empty) => { *state = ReplyState::Pending(receiver, waiter); false }
+                    Err(oneshot::error::TryRecvError::Closed) => {
+                        waiter.post_office.cancel(&waiter.key);
+                        *state = ReplyState::Ready(Err("No response from session".to_string()));
+                        true
+                    }
+                },
+                ready @ ReplyState::Ready(_) => { *state = ready; true }
+                ReplyState::Taken => true,
+            },
+        }
+    }
+}
+
+impl Drop for Promise {
+    fn drop(&mut self) {
+        // If a correlated reply was never collected (the Promise was
+        // dropped without `wait`/`is_done` ever observing a result), the
+        // post office would otherwise hold its waiter forever.
+        if let Kind::Reply(ReplyState::Pending(_, waiter)) = &self.kind {
+            waiter.post_office.cancel(&waiter.key);
+        }
+    }
+}
+
+impl Promise {
+    fn recv<T>(py: Python<'_>, receiver: oneshot::Receiver<T>, timeout: Option<f64>) -> PyResult<T> {
+        py.allow_threads(|| match timeout {
+            Some(secs) => crate::driver::tokio()
+                .block_on(tokio::time::timeout(Duration::from_secs_f64(secs), receiver))
+                .map_err(|_| PyErr::new::<PyTimeoutError, _>("Promise timed out"))?
+                .map_err(|_| PyErr::new::<PyRuntimeError, _>("No response from session")),
+            None => receiver.blocking_recv()
+                .map_err(|_| PyErr::new::<PyRuntimeError, _>("No response from session")),
+        })
+    }
+}
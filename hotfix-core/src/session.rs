@@ -3,12 +3,13 @@ use hotfix::application::{InboundDecision as RustInboundDecision, OutboundDecisi
 use hotfix::config::Config;
 use hotfix::initiator::Initiator;
 use hotfix::store::file::FileStore;
+use hotfix_message::TagU32;
 use pyo3::{pyclass, pymethods, PyResult, PyErr, Py, PyAny, Python};
 use std::sync::Arc;
-use std::thread;
-use tokio::runtime::Runtime;
 use tokio::sync::{mpsc, oneshot, Mutex};
 use crate::message::Message;
+use crate::post_office::PostOffice;
+use crate::promise::Promise;
 
 /// Python enum for inbound message decisions
 #[pyclass]
@@ -55,11 +56,15 @@ enum SessionCommand {
     Shutdown,
 }
 
-/// Python-exposed FIX session that wraps a HotFIX Initiator
+/// Python-exposed FIX session that wraps a HotFIX Initiator.
+///
+/// A lightweight handle: the actual I/O loop runs as a task on the
+/// process-wide runtime (see [`crate::driver::tokio`]), so `Session` itself
+/// only needs to remember how to talk to it.
 #[pyclass]
 pub struct Session {
     command_tx: mpsc::UnboundedSender<SessionCommand>,
-    runtime_thread: Option<thread::JoinHandle<()>>,
+    post_office: Arc<PostOffice>,
 }
 
 #[pymethods]
@@ -68,80 +73,136 @@ impl Session {
     #[new]
     fn new(config_path: String, application: Py<PyAny>) -> PyResult<Self> {
         let (command_tx, mut command_rx) = mpsc::unbounded_channel();
-
-        let runtime_thread = thread::spawn(move || {
-            let runtime = Runtime::new().expect("Failed to create Tokio runtime");
-            runtime.block_on(async move {
-                // Load config
-                let mut config = Config::load_from_path(&config_path);
-                let session_config = config.sessions.pop().expect("Config must include a session");
-
-                // Create Python application wrapper
-                let app = PythonApplication::new(application);
-
-                // Create store
-                let store = FileStore::new("messages", "hotfix-py")
-                    .expect("Failed to create message store");
-
-                // Start initiator
-                let initiator = Initiator::start(session_config, app, store).await;
-
-                // Event loop - process commands from Python
-                while let Some(cmd) = command_rx.recv().await {
-                    match cmd {
-                        SessionCommand::SendMessage { message, response_tx } => {
+        let mut shutdown_rx = crate::driver::shutdown_signal();
+        let post_office = Arc::new(PostOffice::default());
+        let app_post_office = post_office.clone();
+
+        crate::driver::tokio().spawn(async move {
+            // Load config
+            let mut config = Config::load_from_path(&config_path);
+            let session_config = config.sessions.pop().expect("Config must include a session");
+
+            // Create Python application wrapper
+            let app = PythonApplication::new(application, app_post_office);
+
+            // Create store
+            let store = FileStore::new("messages", "hotfix-py")
+                .expect("Failed to create message store");
+
+            // Start initiator
+            let initiator = Initiator::start(session_config, app, store).await;
+
+            // Event loop - process commands from Python until told to stop,
+            // either directly or via the Driver's shared shutdown signal.
+            loop {
+                tokio::select! {
+                    cmd = command_rx.recv() => match cmd {
+                        Some(SessionCommand::SendMessage { message, response_tx }) => {
                             let result = initiator.send_message(message).await
                                 .map_err(|e| e.to_string());
                             response_tx.send(result).ok();
                         }
-                        SessionCommand::Shutdown => break,
-                    }
+                        Some(SessionCommand::Shutdown) | None => break,
+                    },
+                    _ = shutdown_rx.recv() => break,
                 }
-            });
+            }
         });
 
-        Ok(Session {
-            command_tx,
-            runtime_thread: Some(runtime_thread),
-        })
+        Ok(Session { command_tx, post_office })
     }
 
-    /// Send a FIX message to the counterparty
-    fn send_message(&self, message: Message) -> PyResult<()> {
+    /// Send a FIX message to the counterparty.
+    ///
+    /// Returns immediately with a [`Promise`] rather than blocking, so
+    /// Python can fire off many messages and collect their results later
+    /// (e.g. by wrapping the `Promise` in an `asyncio.Future` via a thread
+    /// executor).
+    ///
+    /// If `correlation_tag`/`correlation_value` are given together (e.g.
+    /// 11/ClOrdID), the `Promise` instead resolves with the inbound
+    /// `Message` that echoes that tag/value back, rather than with a bare
+    /// acknowledgement. Supplying only one of the pair is an error, as is
+    /// correlating on a tag/value pair that's already awaited elsewhere.
+    #[pyo3(signature = (message, correlation_tag=None, correlation_value=None))]
+    fn send_message(
+        &self,
+        message: Message,
+        correlation_tag: Option<u32>,
+        correlation_value: Option<String>,
+    ) -> PyResult<Promise> {
+        // Validate the correlation arguments and register the waiter
+        // *before* dispatching the send: once `command_tx.send` succeeds
+        // the message is on its way to the counterparty, so raising after
+        // that point would make Python see a failed `send_message` and
+        // plausibly retry a message that already went out. Registering
+        // first also closes the window where a fast reply could reach
+        // `PostOffice::dispatch` before the waiter exists.
+        let reply_waiter = match (correlation_tag, correlation_value) {
+            (Some(tag), Some(value)) => {
+                let tag = TagU32::new(tag)
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("Invalid tag"))?;
+                let key = (tag, value.into_bytes());
+
+                let (reply_tx, reply_rx) = oneshot::channel();
+                self.post_office.register(key.clone(), reply_tx)
+                    .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e))?;
+
+                Some((key, reply_rx))
+            }
+            (None, None) => None,
+            _ => return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "correlation_tag and correlation_value must be given together",
+            )),
+        };
+
         let (response_tx, response_rx) = oneshot::channel();
 
-        self.command_tx.send(SessionCommand::SendMessage {
-            message,
-            response_tx,
-        }).map_err(|_| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("Session closed"))?;
+        if self.command_tx.send(SessionCommand::SendMessage { message, response_tx }).is_err() {
+            if let Some((key, _)) = &reply_waiter {
+                self.post_office.cancel(key);
+            }
+            return Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("Session closed"));
+        }
+
+        match reply_waiter {
+            Some((key, reply_rx)) => {
+                // If the send itself failed, the reply will never arrive -
+                // clean up the waiter rather than leaking it.
+                let post_office = self.post_office.clone();
+                let watch_key = key.clone();
+                crate::driver::tokio().spawn(async move {
+                    if !matches!(response_rx.await, Ok(Ok(()))) {
+                        post_office.cancel(&watch_key);
+                    }
+                });
 
-        response_rx.blocking_recv()
-            .map_err(|_| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("No response from session"))?
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e))
+                Ok(Promise::reply(reply_rx, self.post_office.clone(), key))
+            }
+            None => Ok(Promise::ack(response_rx)),
+        }
     }
 }
 
 impl Drop for Session {
     fn drop(&mut self) {
-        // Send shutdown command
+        // Best-effort: the event loop task on the shared runtime cleans
+        // itself up once it sees this, or once Driver::stop fires.
         self.command_tx.send(SessionCommand::Shutdown).ok();
-
-        // Wait for runtime thread to finish
-        if let Some(thread) = self.runtime_thread.take() {
-            thread.join().ok();
-        }
     }
 }
 
 /// Application that calls Python callbacks
 struct PythonApplication {
     callback: Arc<Mutex<Py<PyAny>>>,
+    post_office: Arc<PostOffice>,
 }
 
 impl PythonApplication {
-    fn new(callback: Py<PyAny>) -> Self {
+    fn new(callback: Py<PyAny>, post_office: Arc<PostOffice>) -> Self {
         PythonApplication {
             callback: Arc::new(Mutex::new(callback)),
+            post_office,
         }
     }
 }
@@ -153,9 +214,12 @@ impl Application<Message> for PythonApplication {
         let msg_clone = msg.clone();
 
         tokio::task::spawn_blocking(move || {
-            Python::attach(|py| {
-                let callback = callback.blocking_lock();
+            // Acquire the callback lock before touching the GIL: if a Python
+            // thread is holding the lock while waiting on the GIL, attaching
+            // here first would deadlock the two against each other.
+            let callback = callback.blocking_lock();
 
+            Python::attach(|py| {
                 // Call on_outbound_message method
                 match callback.call_method1(py, "on_outbound_message", (msg_clone,)) {
                     Ok(result) => {
@@ -180,12 +244,18 @@ impl Application<Message> for PythonApplication {
     }
 
     async fn on_inbound_message(&self, msg: Message) -> RustInboundDecision {
+        // A waiter registered via `send_message`'s correlation tag takes
+        // this message before it ever reaches the Python callback.
+        if self.post_office.dispatch(&msg) {
+            return RustInboundDecision::Accept;
+        }
+
         let callback = self.callback.clone();
 
         tokio::task::spawn_blocking(move || {
-            Python::attach(|py| {
-                let callback = callback.blocking_lock();
+            let callback = callback.blocking_lock();
 
+            Python::attach(|py| {
                 // Call on_inbound_message method
                 match callback.call_method1(py, "on_inbound_message", (msg,)) {
                     Ok(result) => {
@@ -214,9 +284,9 @@ impl Application<Message> for PythonApplication {
         let reason = reason.to_string();
 
         tokio::task::spawn_blocking(move || {
-            Python::attach(|py| {
-                let callback = callback.blocking_lock();
+            let callback = callback.blocking_lock();
 
+            Python::attach(|py| {
                 if let Err(e) = callback.call_method1(py, "on_logout", (reason,)) {
                     eprintln!("Error calling on_logout: {}", e);
                 }
@@ -230,9 +300,9 @@ impl Application<Message> for PythonApplication {
         let callback = self.callback.clone();
 
         tokio::task::spawn_blocking(move || {
-            Python::attach(|py| {
-                let callback = callback.blocking_lock();
+            let callback = callback.blocking_lock();
 
+            Python::attach(|py| {
                 if let Err(e) = callback.call_method0(py, "on_logon") {
                     eprintln!("Error calling on_logon: {}", e);
                 }